@@ -0,0 +1,145 @@
+//! Implementation of the [`fluent`](crate::fluent) attribute macro, kept separate from the
+//! crate root so it can be exercised with plain `proc_macro2::TokenStream`s.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse2,
+    punctuated::Punctuated,
+    FnArg, ImplItem, ImplItemFn, ItemImpl, LitStr, Meta, ReturnType, Token,
+};
+
+/// Options parsed out of `#[fluent(..)]` on the `impl` block itself.
+struct Options {
+    prefix: String,
+    suffix: String,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            prefix: "with_".to_string(),
+            suffix: String::new(),
+        }
+    }
+}
+
+impl Parse for Options {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut options = Options::default();
+        let metas = Punctuated::<Meta, Token![,]>::parse_terminated(input)?;
+
+        for meta in metas {
+            let name_value = meta.require_name_value()?;
+            let value: LitStr = parse2(name_value.value.to_token_stream())?;
+
+            if name_value.path.is_ident("prefix") {
+                options.prefix = value.value();
+            } else if name_value.path.is_ident("suffix") {
+                options.suffix = value.value();
+            } else {
+                return Err(syn::Error::new_spanned(
+                    &name_value.path,
+                    "unknown `fluent` option, expected `prefix` or `suffix`",
+                ));
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+/// Expands a `#[fluent]`-annotated `impl` block, appending a chain-friendly, owned-returning
+/// sibling for every `fn(&mut self, ..)` method that returns `()` and isn't marked
+/// `#[fluent(skip)]`.
+pub(crate) fn expand(attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let options: Options = parse2(attr)?;
+    let mut input: ItemImpl = parse2(item)?;
+
+    let mut generated = Vec::new();
+
+    for item in &mut input.items {
+        let ImplItem::Fn(method) = item else {
+            continue;
+        };
+
+        // The helper attribute is only meaningful to us, so strip it before re-emitting the
+        // original method; otherwise rustc would reject it as an unknown attribute.
+        let skip = has_skip_attribute(&method.attrs)?;
+        method.attrs.retain(|attr| !attr.path().is_ident("fluent"));
+
+        if skip || !takes_mut_self(method) || !returns_unit(method) {
+            continue;
+        }
+
+        let original_name = &method.sig.ident;
+        // For a method following the `set_x` convention, the generated name is derived from the
+        // stripped subject (`x`), not the original ident (`set_x`); other names are used whole.
+        let original_name_string = original_name.to_string();
+        let subject = original_name_string
+            .strip_prefix("set_")
+            .unwrap_or(&original_name_string);
+        let new_name = format_ident!("{}{}{}", options.prefix, subject, options.suffix);
+        let generics = &method.sig.generics;
+        let inputs = method.sig.inputs.iter().skip(1);
+        let args = method.sig.inputs.iter().skip(1).map(|arg| match arg {
+            FnArg::Typed(typed) => &typed.pat,
+            FnArg::Receiver(_) => unreachable!("receiver is always the first argument"),
+        });
+
+        generated.push(quote! {
+            #[doc = concat!("Owned, chain-friendly version of [`", stringify!(#original_name), "`](Self::", stringify!(#original_name), ").")]
+            pub fn #new_name #generics(mut self, #(#inputs),*) -> Self {
+                self.#original_name(#(#args),*);
+                self
+            }
+        });
+    }
+
+    let self_ty = &input.self_ty;
+    let (impl_generics, _, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #input
+
+        impl #impl_generics #self_ty #where_clause {
+            #(#generated)*
+        }
+    })
+}
+
+fn takes_mut_self(method: &ImplItemFn) -> bool {
+    matches!(
+        method.sig.inputs.first(),
+        Some(FnArg::Receiver(receiver)) if receiver.mutability.is_some() && receiver.reference.is_some()
+    )
+}
+
+fn returns_unit(method: &ImplItemFn) -> bool {
+    matches!(method.sig.output, ReturnType::Default)
+}
+
+fn has_skip_attribute(attrs: &[syn::Attribute]) -> syn::Result<bool> {
+    for attr in attrs {
+        if !attr.path().is_ident("fluent") {
+            continue;
+        }
+
+        let mut skip = false;
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                skip = true;
+                Ok(())
+            } else {
+                Err(meta.error("unknown `fluent` method option, expected `skip`"))
+            }
+        })?;
+
+        if skip {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
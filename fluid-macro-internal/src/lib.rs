@@ -0,0 +1,58 @@
+//! Implementation details behind [`fluid_macro`]'s `#[fluent]` attribute.
+//!
+//! This lives in its own crate because a `proc-macro = true` crate can only export
+//! `#[proc_macro]`/`#[proc_macro_attribute]`/`#[proc_macro_derive]` functions — it can't also
+//! export the `#[macro_export] macro_rules! fluid` that lives in the `fluid_macro` crate. The
+//! `fluid_macro` crate re-exports [`fluent`] from here instead.
+
+mod expand;
+
+use proc_macro::TokenStream;
+
+/// Attribute macro that generates chain-friendly, owned-returning setters from `&mut self`
+/// methods returning `()`. See the `fluid_macro` crate documentation for an example.
+///
+/// Applied to an `impl` block, it scans for methods that take `&mut self` and return `()`, such
+/// as `fn set_x(&mut self, ..)`, and generates a sibling `fn with_x(mut self, ..) -> Self` that
+/// calls the original method and returns `self`. The generated methods are exactly the shape `fluid!` expects, so the two
+/// macros compose.
+///
+/// # Options
+///
+/// The generated name is the original name with any leading `set_` removed, prefixed with
+/// `with_` by default. The prefix and suffix can be customized on the `impl` block:
+///
+/// ```rust
+/// # use fluid_macro_internal::fluent;
+/// # struct Example { value: i32 }
+/// #[fluent(prefix = "", suffix = "_owned")]
+/// impl Example {
+///     fn set_value(&mut self, value: i32) {
+///         self.value = value;
+///     }
+/// }
+/// ```
+///
+/// generates `value_owned` instead of `with_value`.
+///
+/// # Opting out
+///
+/// Mark an individual method with `#[fluent(skip)]` to exclude it from generation:
+///
+/// ```rust
+/// # use fluid_macro_internal::fluent;
+/// # struct Example { value: i32 }
+/// #[fluent]
+/// impl Example {
+///     #[fluent(skip)]
+///     fn set_value(&mut self, value: i32) {
+///         self.value = value;
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn fluent(attr: TokenStream, item: TokenStream) -> TokenStream {
+    expand::expand(attr.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -116,28 +116,259 @@
 //! assert_eq!(x, "10");
 //! ```
 //!
-//! # Known limitations
+//! # Turbofish
+//!
+//! Explicit generic arguments are supported, both in the plain and nested block forms:
 //!
-//! You can't turbofish.
+//! ```rust
+//! # #[macro_use] extern crate fluid_macro;
+//! # use fluid_macro::fluid;
 //!
-//! ```ignore
+//! # fn main() {
 //! let x = fluid!("123", {
-//!     parse::<i32>(); // will not compile!
+//!     parse::<i32>();
 //!     unwrap_or_default();
 //!     clamp(5, 100);
 //!     to_string();
-//! })
+//! });
+//!
+//! assert_eq!(x, "100");
+//! # }
+//! ```
+//!
+//! # Fallible steps
+//!
+//! A step can end in `?;` instead of `;` to thread the try operator through the chain, which is
+//! handy for nested value extraction where each hop can fail:
+//!
+//! ```rust
+//! # #[macro_use] extern crate fluid_macro;
+//! # use fluid_macro::fluid;
+//! # use std::collections::HashMap;
+//!
+//! fn get(map: &HashMap<&str, HashMap<&str, String>>) -> Option<String> {
+//!     Some(fluid!(map, {
+//!         get("a")?;
+//!         get("b")?;
+//!         to_owned();
+//!     }))
+//! }
+//!
+//! # fn main() {
+//! let mut inner = HashMap::new();
+//! inner.insert("b", "found it".to_string());
+//! let mut map = HashMap::new();
+//! map.insert("a", inner);
+//!
+//! assert_eq!(get(&map), Some("found it".to_string()));
+//! assert_eq!(get(&HashMap::new()), None);
+//! # }
 //! ```
 //!
+//! This composes with turbofish, which is handy since a call like `parse` almost always needs
+//! both:
+//!
+//! ```rust
+//! # #[macro_use] extern crate fluid_macro;
+//! # use fluid_macro::fluid;
+//!
+//! fn double(s: &str) -> Result<i32, std::num::ParseIntError> {
+//!     Ok(fluid!(s, {
+//!         parse::<i32>()?;
+//!         [* 2];
+//!     }))
+//! }
+//!
+//! # fn main() {
+//! assert_eq!(double("21"), Ok(42));
+//! assert!(double("nope").is_err());
+//! # }
+//! ```
+//!
+//! # Conditionals and loops
+//!
+//! An `if` step runs a sub-chain over the accumulated value when the condition holds, and passes
+//! the value through unchanged otherwise. Both branches have to produce the same type. The
+//! condition must be parenthesized:
+//!
+//! ```rust
+//! # #[macro_use] extern crate fluid_macro;
+//! # use fluid_macro::fluid;
+//!
+//! # fn main() {
+//! let n = 5i32;
+//! let x = fluid!(n, {
+//!     if (n > 0) {
+//!         [* 2];
+//!     }
+//!     to_string();
+//! });
+//!
+//! assert_eq!(x, "10");
+//! # }
+//! ```
+//!
+//! A `for` step folds a sub-chain over the value once per iteration. The iterator must be
+//! parenthesized for the same reason:
+//!
+//! ```rust
+//! # #[macro_use] extern crate fluid_macro;
+//! # use fluid_macro::fluid;
+//!
+//! # fn main() {
+//! let x = fluid!(0i32, {
+//!     for i in (1..=3) {
+//!         [+ i];
+//!     }
+//!     to_string();
+//! });
+//!
+//! assert_eq!(x, "6");
+//! # }
+//! ```
+//!
+//! # Named bindings and `tap`
+//!
+//! A `let $name;` step binds the value accumulated so far to a name, which can then be used in
+//! the arguments of later steps:
+//!
+//! ```rust
+//! # #[macro_use] extern crate fluid_macro;
+//! # use fluid_macro::fluid;
+//!
+//! # fn main() {
+//! let x = fluid!(5i32, {
+//!     let base;
+//!     [* 2];
+//!     [+ base];
+//!     to_string();
+//! });
+//!
+//! assert_eq!(x, "15");
+//! # }
+//! ```
+//!
+//! A `tap |$name| { ... }` step runs a block with the current value bound to `$name`, for side
+//! effects such as logging or assertions, then continues the chain with the value unchanged:
+//!
+//! ```rust
+//! # #[macro_use] extern crate fluid_macro;
+//! # use fluid_macro::fluid;
+//!
+//! # fn main() {
+//! let mut seen = None;
+//! let x = fluid!(5i32, {
+//!     [* 2];
+//!     tap |b| {
+//!         seen = Some(b);
+//!     }
+//!     to_string();
+//! });
+//!
+//! assert_eq!(seen, Some(10));
+//! assert_eq!(x, "10");
+//! # }
+//! ```
+//!
+//! # Generating chain-friendly setters with `#[fluent]`
+//!
+//! Builder types often expose setters shaped `fn set_x(&mut self, ..)` that return `()`, which
+//! don't chain and so have to be wrapped awkwardly in a [`fluid!`](fluid) step. Annotating the
+//! `impl` block with [`fluent`] generates an owned-returning sibling for each such method, named
+//! `with_x` by default:
+//!
+//! ```rust
+//! # #[macro_use] extern crate fluid_macro;
+//! # use fluid_macro::{fluent, fluid};
+//!
+//! struct Example {
+//!     value: i32,
+//! }
+//!
+//! #[fluent]
+//! impl Example {
+//!     fn set_value(&mut self, value: i32) {
+//!         self.value = value;
+//!     }
+//! }
+//!
+//! # fn main() {
+//! let x = fluid!(Example { value: 0 }, {
+//!     with_value(5);
+//! });
+//!
+//! assert_eq!(x.value, 5);
+//! # }
+//! ```
+//!
+//! See the [`fluent`] docs for how to opt individual methods out and how to customize the
+//! generated name.
+//!
+//! # Known limitations
+//!
 //! It's not very friendly to the IDE whilst writing. You will have to already know the names
 //! of methods you want to use. After compilation, however, symbol lookup and the like works fine.
 
+// `#[fluent]` is a `#[proc_macro_attribute]`, and a `proc-macro = true` crate can't also export
+// the `#[macro_export] macro_rules! fluid` below, so its implementation lives in the sibling
+// `fluid-macro-internal` crate and is re-exported here.
+pub use fluid_macro_internal::fluent;
+
 /// General-purpose macro that allows you to write long method chains as a series of
 /// statements. See the crate documentation for more details.
 #[macro_export]
 macro_rules! fluid {
     // Base case: There's no more calls to combine, so just resolve to the final builder.
     ($expr:expr, {}) => { $expr };
+    // Expression-shaped calls.
+    ($expr:expr, { [$($items:tt)+]; $($next:tt)* }) => {
+        $crate::fluid!(($expr $($items)+), { $($next)*} )
+    };
+    // Fallible expression-shaped calls: Same as the expression-shaped case above, but applies
+    // the try operator to the result.
+    ($expr:expr, { [$($items:tt)+]?; $($next:tt)* }) => {
+        $crate::fluid!(($expr $($items)+)?, { $($next)*} )
+    };
+    // Named binding case: Binds the value accumulated so far to a name, usable in later steps.
+    ($expr:expr, { let $name:ident; $($next:tt)* }) => {
+        { let $name = $expr; $crate::fluid!($name, { $($next)* }) }
+    };
+    // Tap case: Runs a block with the current value bound to the caller-supplied name for its
+    // side effects, then continues the chain with the value unchanged. The name has to come from
+    // the caller (rather than being a literal `b` in the template) so it's visible to `$body`:
+    // an identifier introduced by the macro's own template is hygienic and invisible to tokens
+    // the caller passed in.
+    ($expr:expr, { tap |$name:ident| { $($body:tt)* } $($next:tt)* }) => {
+        { let $name = $expr; $($body)* $crate::fluid!($name, { $($next)* }) }
+    };
+    // Conditional case: Runs the sub-chain over the value when the condition holds, and passes
+    // the value through unchanged otherwise. Both branches must resolve to the same type.
+    // The condition must be parenthesized: an `expr` fragment may only be followed by `=>`, `,`
+    // or `;`, so it can't be matched directly before the opening `{` of the sub-chain. This arm
+    // must also come before the nesting case below: `ident` fragments hard-error (rather than
+    // simply failing to match) when the next token is a keyword like `if`, so the nesting case
+    // never gets a chance to reject it and fall through.
+    ($expr:expr, { if ($cond:expr) { $($children:tt)+ } $($next:tt)* }) => {
+        $crate::fluid!(
+            if $cond { $crate::fluid!($expr, { $($children)+ }) } else { $expr },
+            { $($next)* }
+        )
+    };
+    // Loop case: Folds the sub-chain over the value once per iteration. The iterator must be
+    // parenthesized for the same reason the condition above is, and for the same reason this
+    // arm must also precede the nesting case below.
+    ($expr:expr, { for $pat:pat in ($iter:expr) { $($children:tt)+ } $($next:tt)* }) => {
+        $crate::fluid!(
+            {
+                let mut acc = $expr;
+                for $pat in $iter {
+                    acc = $crate::fluid!(acc, { $($children)+ });
+                }
+                acc
+            },
+            { $($next)* }
+        )
+    };
     // Nesting case: Use this macro recursively in order to handle each nested branch.
     ($expr:expr, { $block:ident($($args:expr),*) { $($children:tt)+ } $($next:tt)* }) => {
         $crate::fluid!(
@@ -145,9 +376,28 @@ macro_rules! fluid {
             { $($next)* }
         )
     };
-    // Expression-shaped calls.
-    ($expr:expr, { [$($items:tt)+]; $($next:tt)* }) => {
-        $crate::fluid!(($expr $($items)+), { $($next)*} )
+    // Turbofish nesting case: Same as the nesting case above, but for calls with explicit
+    // generic arguments.
+    ($expr:expr, { $command:ident::<$($ty:ty),* $(,)?>($($args:expr),*) { $($children:tt)+ } $($next:tt)* }) => {
+        $crate::fluid!(
+            $expr.$command::<$($ty),*>($($args,)* |b| $crate::fluid!(b, { $($children)+ })),
+            { $($next)* }
+        )
+    };
+    // Turbofish fallible case: Same as the fallible case below, but for calls with explicit
+    // generic arguments.
+    ($expr:expr, { $command:ident::<$($ty:ty),* $(,)?>($($args:expr),*)?; $($next:tt)* }) => {
+        $crate::fluid!(($expr.$command::<$($ty),*>($($args),*)?), { $($next)* })
+    };
+    // Turbofish default case: Same as the default case below, but for calls with explicit
+    // generic arguments.
+    ($expr:expr, { $command:ident::<$($ty:ty),* $(,)?>($($args:expr),*); $($next:tt)* }) => {
+        $crate::fluid!($expr.$command::<$($ty),*>($($args),*), { $($next)* })
+    };
+    // Fallible case: Same as the default case below, but applies the try operator to the call's
+    // result.
+    ($expr:expr, { $command:ident($($args:expr),*)?; $($next:tt)* }) => {
+        $crate::fluid!(($expr.$command($($args),*)?), { $($next)* })
     };
     // Default case: Take one line and turn it into a chained call.
     ($expr:expr, { $command:ident($($args:expr),*); $($next:tt)* }) => {